@@ -1,24 +1,30 @@
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use clap::Parser;
 use rayon::prelude::*;
+use solana_sdk::derivation_path::DerivationPath;
 use solana_sdk::signature::{Keypair, Signer};
-use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use solana_sdk::signer::keypair::{keypair_from_seed, keypair_from_seed_and_derivation_path, write_keypair_file};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+const BASE58_CHARS: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Generate Solana vanity addresses", long_about = None)]
 struct Args {
-    /// Prefix pattern to match
-    #[arg(short, long)]
-    prefix: Option<String>,
+    /// Prefix pattern with target count, e.g. "Sol:3" (repeatable)
+    #[arg(long = "starts-with", value_name = "PREFIX:COUNT")]
+    starts_with: Vec<String>,
 
-    /// Suffix pattern to match
-    #[arg(short, long)]
-    suffix: Option<String>,
+    /// Suffix pattern with target count, e.g. "xyz:2" (repeatable)
+    #[arg(long = "ends-with", value_name = "SUFFIX:COUNT")]
+    ends_with: Vec<String>,
 
-    /// Number of addresses to generate (default: 1)
-    #[arg(short = 'n', long, default_value = "1")]
-    count: usize,
+    /// Prefix and suffix pattern with target count, e.g. "Sol:xyz:1" (repeatable)
+    #[arg(long = "starts-and-ends-with", value_name = "PREFIX:SUFFIX:COUNT")]
+    starts_and_ends_with: Vec<String>,
 
     /// Number of threads to use (default: number of CPU cores)
     #[arg(short, long)]
@@ -31,76 +37,344 @@ struct Args {
     /// Show attempts per second
     #[arg(short = 'v', long)]
     verbose: bool,
+
+    /// Directory to write each found keypair as <address>.json
+    #[arg(short = 'o', long = "output-dir", value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
+    /// Overwrite existing keypair files in --output-dir
+    #[arg(long)]
+    force: bool,
+
+    /// Derive each candidate from a freshly generated BIP39 mnemonic so it can
+    /// be restored with `solana-keygen recover` (much slower than the default)
+    #[arg(long = "use-mnemonic")]
+    use_mnemonic: bool,
+
+    /// BIP39 word count to use with --use-mnemonic (12, 15, 18, 21 or 24)
+    #[arg(long = "word-count", default_value = "12", value_parser = parse_word_count)]
+    word_count: usize,
+
+    /// BIP39 mnemonic language to use with --use-mnemonic
+    #[arg(long = "language", default_value = "english", value_parser = parse_language)]
+    language: Language,
+
+    /// Optional BIP39 passphrase to combine with the mnemonic
+    #[arg(long = "passphrase", default_value = "")]
+    passphrase: String,
+
+    /// Optional derivation path, e.g. "m/44'/501'/0'/0'" (requires --use-mnemonic)
+    #[arg(long = "derivation-path")]
+    derivation_path: Option<String>,
+
+    /// Confirm running a search whose estimated attempts exceed 10^12
+    #[arg(long)]
+    yes: bool,
 }
 
-struct VanityMatcher {
-    prefix: Option<String>,
-    suffix: Option<String>,
-    case_sensitive: bool,
+/// Above this many expected attempts, a search is unlikely to finish in a
+/// reasonable amount of time and requires an explicit --yes to proceed.
+const DIFFICULTY_CONFIRMATION_THRESHOLD: f64 = 1e12;
+
+fn parse_word_count(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if matches!(n, 12 | 15 | 18 | 21 | 24) => Ok(n),
+        _ => Err(format!("word count must be one of 12, 15, 18, 21, 24, got '{}'", s)),
+    }
 }
 
-impl VanityMatcher {
-    fn new(prefix: Option<String>, suffix: Option<String>, case_sensitive: bool) -> Result<Self, String> {
-        if prefix.is_none() && suffix.is_none() {
-            return Err("At least one of --prefix or --suffix must be specified".to_string());
+fn mnemonic_type_from_word_count(word_count: usize) -> MnemonicType {
+    match word_count {
+        12 => MnemonicType::Words12,
+        15 => MnemonicType::Words15,
+        18 => MnemonicType::Words18,
+        21 => MnemonicType::Words21,
+        24 => MnemonicType::Words24,
+        _ => unreachable!("word_count is validated by parse_word_count"),
+    }
+}
+
+fn parse_language(s: &str) -> Result<Language, String> {
+    match s.to_lowercase().as_str() {
+        "english" => Ok(Language::English),
+        "chinese-simplified" => Ok(Language::ChineseSimplified),
+        "chinese-traditional" => Ok(Language::ChineseTraditional),
+        "french" => Ok(Language::French),
+        "italian" => Ok(Language::Italian),
+        "japanese" => Ok(Language::Japanese),
+        "korean" => Ok(Language::Korean),
+        "spanish" => Ok(Language::Spanish),
+        other => Err(format!("unsupported BIP39 language: '{}'", other)),
+    }
+}
+
+/// Parses an absolute derivation path like "m/44'/501'/0'/0'" into a
+/// `DerivationPath`. Solana keypairs always live under the `m/44'/501'`
+/// (SLIP-44 Solana) prefix, so we strip that fixed prefix and delegate the
+/// remaining `account'[/change']` segments to the public
+/// `DerivationPath::from_key_str`, rather than reaching into solana-sdk's
+/// private path-building internals.
+fn parse_derivation_path(path: &str) -> Result<DerivationPath, String> {
+    let suffix = path
+        .strip_prefix("m/44'/501'")
+        .ok_or_else(|| format!("derivation path must start with \"m/44'/501'\", got '{}'", path))?
+        .trim_start_matches('/');
+
+    let key_str = if suffix.is_empty() { "0'" } else { suffix };
+
+    DerivationPath::from_key_str(key_str)
+        .map_err(|e| format!("invalid derivation path '{}': {}", path, e))
+}
+
+/// Settings for deriving recoverable keypairs from a freshly generated BIP39
+/// mnemonic, used in place of the fast `Keypair::new()` path.
+struct MnemonicConfig {
+    mnemonic_type: MnemonicType,
+    language: Language,
+    passphrase: String,
+    derivation_path: Option<DerivationPath>,
+}
+
+/// Generates one candidate keypair, optionally deriving it from a fresh BIP39
+/// mnemonic instead of raw random bytes. Returns the mnemonic phrase alongside
+/// the keypair so a hit can be restored with `solana-keygen recover`.
+fn generate_candidate(mnemonic_config: Option<&MnemonicConfig>) -> (Keypair, Option<String>) {
+    match mnemonic_config {
+        None => (Keypair::new(), None),
+        Some(cfg) => {
+            let mnemonic = Mnemonic::new(cfg.mnemonic_type, cfg.language);
+            let seed = Seed::new(&mnemonic, &cfg.passphrase);
+            let keypair = match &cfg.derivation_path {
+                Some(path) => keypair_from_seed_and_derivation_path(seed.as_bytes(), Some(path.clone())),
+                None => keypair_from_seed(seed.as_bytes()),
+            }
+            .expect("a freshly generated BIP39 seed always yields a valid keypair");
+            (keypair, Some(mnemonic.phrase().to_string()))
         }
+    }
+}
+
+/// A single requested grind pattern together with how many matches are still wanted.
+struct GrindMatch {
+    starts: String,
+    ends: String,
+    target: u64,
+    remaining: AtomicU64,
+}
 
-        // Validate Base58 characters
-        let base58_chars = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
-        
-        let validated_prefix = if let Some(p) = prefix {
-            if p.is_empty() {
-                return Err("Prefix cannot be empty".to_string());
+impl GrindMatch {
+    fn new(starts: String, ends: String, target: u64) -> Self {
+        GrindMatch {
+            starts,
+            ends,
+            target,
+            remaining: AtomicU64::new(target),
+        }
+    }
+
+    /// Checks `address` against this pattern without any heap allocation:
+    /// Base58 output is pure ASCII, so we compare the relevant byte slices
+    /// directly with `eq_ignore_ascii_case` instead of lowercasing `address`.
+    fn is_match(&self, address: &str, case_sensitive: bool) -> bool {
+        let address = address.as_bytes();
+
+        if !self.starts.is_empty() {
+            let prefix = self.starts.as_bytes();
+            match address.get(..prefix.len()) {
+                Some(candidate) if bytes_match(candidate, prefix, case_sensitive) => {}
+                _ => return false,
             }
-            for c in p.chars() {
-                if !base58_chars.contains(c) {
-                    return Err(format!("Invalid Base58 character in prefix: '{}'", c));
-                }
+        }
+
+        if !self.ends.is_empty() {
+            let suffix = self.ends.as_bytes();
+            match address.len().checked_sub(suffix.len()).and_then(|start| address.get(start..)) {
+                Some(candidate) if bytes_match(candidate, suffix, case_sensitive) => {}
+                _ => return false,
             }
-            Some(if case_sensitive { p } else { p.to_lowercase() })
-        } else {
-            None
-        };
+        }
 
-        let validated_suffix = if let Some(s) = suffix {
-            if s.is_empty() {
-                return Err("Suffix cannot be empty".to_string());
+        true
+    }
+
+    /// Atomically claims one of the remaining slots for this pattern, returning
+    /// `true` if a slot was available. Uses a CAS loop so concurrent workers
+    /// can never drive `remaining` below zero.
+    fn try_claim(&self) -> bool {
+        let mut current = self.remaining.load(Ordering::Relaxed);
+        loop {
+            if current == 0 {
+                return false;
             }
-            for c in s.chars() {
-                if !base58_chars.contains(c) {
-                    return Err(format!("Invalid Base58 character in suffix: '{}'", c));
-                }
+            match self.remaining.compare_exchange_weak(
+                current,
+                current - 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
             }
-            Some(if case_sensitive { s } else { s.to_lowercase() })
-        } else {
-            None
-        };
+        }
+    }
 
-        Ok(VanityMatcher { 
-            prefix: validated_prefix, 
-            suffix: validated_suffix,
-            case_sensitive,
-        })
+    fn is_done(&self) -> bool {
+        self.remaining.load(Ordering::Relaxed) == 0
     }
 
-    fn matches(&self, address: &str) -> bool {
-        let compare_address = if self.case_sensitive {
-            address.to_string()
-        } else {
-            address.to_lowercase()
-        };
+    /// Expected number of attempts needed to land a single hit for this
+    /// pattern, i.e. `1 / product(p_i)` over every matched character's
+    /// per-position match probability `p_i`.
+    fn expected_attempts_per_hit(&self, case_sensitive: bool) -> f64 {
+        let mut probability = 1.0_f64;
+        for c in self.starts.chars().chain(self.ends.chars()) {
+            let matching_chars = if case_sensitive { 1 } else { case_fold_size(c) };
+            probability *= matching_chars as f64 / BASE58_CHARS.len() as f64;
+        }
+        1.0 / probability
+    }
 
-        let mut matched = true;
+    fn description(&self) -> String {
+        match (self.starts.is_empty(), self.ends.is_empty()) {
+            (false, false) => format!("{}x prefix '{}' and suffix '{}'", self.target, self.starts, self.ends),
+            (false, true) => format!("{}x prefix '{}'", self.target, self.starts),
+            (true, false) => format!("{}x suffix '{}'", self.target, self.ends),
+            (true, true) => unreachable!("a GrindMatch must have a prefix, a suffix, or both"),
+        }
+    }
+}
+
+/// Compares two ASCII byte slices, either exactly or case-insensitively.
+fn bytes_match(candidate: &[u8], pattern: &[u8], case_sensitive: bool) -> bool {
+    if case_sensitive {
+        candidate == pattern
+    } else {
+        candidate.eq_ignore_ascii_case(pattern)
+    }
+}
+
+/// Number of Base58 characters that fold to the same character as the
+/// already-lowercased pattern character `c` under case-insensitive matching,
+/// e.g. `'a'` matches both `'a'` and `'A'` while digits, `'i'` and `'o'` only
+/// match themselves and `'l'` only matches `'L'` (the other case of each is
+/// not in the Base58 alphabet). Any other character counts as 1.
+fn case_fold_size(c: char) -> usize {
+    if c.is_ascii_lowercase() && !matches!(c, 'l' | 'i' | 'o') {
+        2
+    } else {
+        1
+    }
+}
 
-        if let Some(ref prefix) = self.prefix {
-            matched = matched && compare_address.starts_with(prefix);
+fn validate_base58(s: &str, label: &str) -> Result<(), String> {
+    if s.is_empty() {
+        return Err(format!("{} cannot be empty", label));
+    }
+    for c in s.chars() {
+        if !BASE58_CHARS.contains(c) {
+            return Err(format!("Invalid Base58 character in {}: '{}'", label, c));
         }
+    }
+    Ok(())
+}
+
+fn parse_count(s: &str, label: &str) -> Result<u64, String> {
+    s.parse::<u64>()
+        .ok()
+        .filter(|n| *n > 0)
+        .ok_or_else(|| format!("{} must be a nonzero integer, got '{}'", label, s))
+}
 
-        if let Some(ref suffix) = self.suffix {
-            matched = matched && compare_address.ends_with(suffix);
+/// Parses a `--starts-with PREFIX:COUNT` argument.
+fn parse_starts_with(spec: &str, case_sensitive: bool) -> Result<GrindMatch, String> {
+    let (prefix, count) = spec
+        .split_once(':')
+        .ok_or_else(|| format!("--starts-with expects PREFIX:COUNT, got '{}'", spec))?;
+    validate_base58(prefix, "prefix")?;
+    let count = parse_count(count, "count")?;
+    let prefix = if case_sensitive { prefix.to_string() } else { prefix.to_lowercase() };
+    Ok(GrindMatch::new(prefix, String::new(), count))
+}
+
+/// Parses an `--ends-with SUFFIX:COUNT` argument.
+fn parse_ends_with(spec: &str, case_sensitive: bool) -> Result<GrindMatch, String> {
+    let (suffix, count) = spec
+        .split_once(':')
+        .ok_or_else(|| format!("--ends-with expects SUFFIX:COUNT, got '{}'", spec))?;
+    validate_base58(suffix, "suffix")?;
+    let count = parse_count(count, "count")?;
+    let suffix = if case_sensitive { suffix.to_string() } else { suffix.to_lowercase() };
+    Ok(GrindMatch::new(String::new(), suffix, count))
+}
+
+/// Parses a `--starts-and-ends-with PREFIX:SUFFIX:COUNT` argument.
+fn parse_starts_and_ends_with(spec: &str, case_sensitive: bool) -> Result<GrindMatch, String> {
+    let parts: Vec<&str> = spec.splitn(3, ':').collect();
+    let [prefix, suffix, count] = parts.as_slice() else {
+        return Err(format!(
+            "--starts-and-ends-with expects PREFIX:SUFFIX:COUNT, got '{}'",
+            spec
+        ));
+    };
+    validate_base58(prefix, "prefix")?;
+    validate_base58(suffix, "suffix")?;
+    let count = parse_count(count, "count")?;
+    let (prefix, suffix) = if case_sensitive {
+        (prefix.to_string(), suffix.to_string())
+    } else {
+        (prefix.to_lowercase(), suffix.to_lowercase())
+    };
+    Ok(GrindMatch::new(prefix, suffix, count))
+}
+
+struct VanityMatcher {
+    grind_matches: Vec<GrindMatch>,
+    case_sensitive: bool,
+}
+
+impl VanityMatcher {
+    fn new(grind_matches: Vec<GrindMatch>, case_sensitive: bool) -> Result<Self, String> {
+        if grind_matches.is_empty() {
+            return Err(
+                "At least one of --starts-with, --ends-with or --starts-and-ends-with must be specified"
+                    .to_string(),
+            );
         }
 
-        matched
+        Ok(VanityMatcher {
+            grind_matches,
+            case_sensitive,
+        })
+    }
+
+    /// Tries to claim a slot for `address` against every requested pattern,
+    /// returning `true` if any pattern accepted it.
+    fn try_claim(&self, address: &str) -> bool {
+        for grind_match in &self.grind_matches {
+            if grind_match.is_match(address, self.case_sensitive) && grind_match.try_claim() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_done(&self) -> bool {
+        self.grind_matches.iter().all(GrindMatch::is_done)
+    }
+
+    fn target_total(&self) -> u64 {
+        self.grind_matches.iter().map(|m| m.target).sum()
+    }
+
+    /// Expected total attempts for the run. Every attempt is checked against
+    /// all patterns at once, so the run is bottlenecked by whichever pattern
+    /// needs the most attempts to collect its `target` hits.
+    fn expected_attempts(&self) -> f64 {
+        self.grind_matches
+            .iter()
+            .map(|m| m.expected_attempts_per_hit(self.case_sensitive) * m.target as f64)
+            .fold(0.0, f64::max)
     }
 
     fn description(&self) -> String {
@@ -109,58 +383,149 @@ impl VanityMatcher {
         } else {
             "case-insensitive"
         };
-        
-        match (&self.prefix, &self.suffix) {
-            (Some(p), Some(s)) => format!("prefix '{}' and suffix '{}' ({})", p, s, sensitivity),
-            (Some(p), None) => format!("prefix '{}' ({})", p, sensitivity),
-            (None, Some(s)) => format!("suffix '{}' ({})", s, sensitivity),
-            (None, None) => unreachable!(),
-        }
+        let patterns: Vec<String> = self.grind_matches.iter().map(GrindMatch::description).collect();
+        format!("{} ({})", patterns.join(", "), sensitivity)
     }
 }
 
+/// A single vanity match: the keypair, its Base58 address, and the BIP39
+/// mnemonic that produced it when `--use-mnemonic` was set.
+struct FoundKey {
+    keypair: Keypair,
+    address: String,
+    mnemonic: Option<String>,
+}
+
+type FoundResults = Arc<Mutex<Vec<FoundKey>>>;
+
 fn generate_vanity_address(
     matcher: Arc<VanityMatcher>,
+    mnemonic_config: Arc<Option<MnemonicConfig>>,
     found: Arc<AtomicBool>,
     attempts: Arc<AtomicU64>,
-    results: Arc<Mutex<Vec<(Keypair, String)>>>,
-    found_count: Arc<AtomicUsize>,
-    target_count: usize,
+    results: FoundResults,
 ) {
     while !found.load(Ordering::Relaxed) {
-        let keypair = Keypair::new();
+        let (keypair, mnemonic) = generate_candidate(mnemonic_config.as_ref().as_ref());
         let pubkey = keypair.pubkey().to_string();
-        
+
         attempts.fetch_add(1, Ordering::Relaxed);
 
-        if matcher.matches(&pubkey) {
+        if matcher.try_claim(&pubkey) {
             let mut results_guard = results.lock().unwrap();
-            results_guard.push((keypair, pubkey.clone()));
-            let current_count = found_count.fetch_add(1, Ordering::Relaxed) + 1;
+            results_guard.push(FoundKey { keypair, address: pubkey, mnemonic });
             drop(results_guard);
-            
-            if current_count >= target_count {
+
+            if matcher.is_done() {
                 found.store(true, Ordering::Relaxed);
             }
         }
     }
 }
 
+/// Runs every worker thread flat-out for about a second to measure real
+/// candidate-generation throughput (accounting for `--use-mnemonic`, which is
+/// far slower than the default path), returning attempts/sec.
+fn calibrate_rate(num_threads: usize, mnemonic_config: &Arc<Option<MnemonicConfig>>) -> f64 {
+    let counter = Arc::new(AtomicU64::new(0));
+    let stop = Arc::new(AtomicBool::new(false));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..num_threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            let stop = Arc::clone(&stop);
+            let mnemonic_config = Arc::clone(mnemonic_config);
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let _ = generate_candidate(mnemonic_config.as_ref().as_ref());
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    stop.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    counter.load(Ordering::Relaxed) as f64 / start.elapsed().as_secs_f64()
+}
+
+/// Formats a duration given in seconds as a short human-readable string.
+fn format_duration(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        return "unknown".to_string();
+    }
+    if seconds < 60.0 {
+        format!("{:.1}s", seconds)
+    } else if seconds < 3600.0 {
+        format!("{:.1}m", seconds / 60.0)
+    } else if seconds < 86400.0 {
+        format!("{:.1}h", seconds / 3600.0)
+    } else {
+        format!("{:.1}d", seconds / 86400.0)
+    }
+}
+
+/// Formats a secret key as the canonical JSON byte array (`[12,34,...]`) that
+/// `solana-keygen recover` and `write_keypair_file` both read and write.
 fn format_secret_key(keypair: &Keypair) -> String {
-    format!("{:?}", keypair.to_bytes())
+    serde_json::to_string(&keypair.to_bytes().to_vec()).expect("keypair bytes are serializable")
+}
+
+/// Writes `keypair` to `<dir>/<address>.json` in the canonical Solana CLI
+/// keypair format, refusing to clobber an existing file unless `force` is set.
+fn write_keypair_json(keypair: &Keypair, dir: &Path, address: &str, force: bool) -> Result<PathBuf, String> {
+    let path = dir.join(format!("{}.json", address));
+    if path.exists() && !force {
+        return Err(format!(
+            "{} already exists (use --force to overwrite)",
+            path.display()
+        ));
+    }
+    write_keypair_file(keypair, &path)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
 }
 
 fn main() {
     let args = Args::parse();
 
-    // Validate count
-    if args.count == 0 {
-        eprintln!("Error: count must be at least 1");
-        std::process::exit(1);
+    // Build the list of requested grind patterns
+    let mut grind_matches = Vec::new();
+    for spec in &args.starts_with {
+        match parse_starts_with(spec, args.case_sensitive) {
+            Ok(m) => grind_matches.push(m),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    for spec in &args.ends_with {
+        match parse_ends_with(spec, args.case_sensitive) {
+            Ok(m) => grind_matches.push(m),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    for spec in &args.starts_and_ends_with {
+        match parse_starts_and_ends_with(spec, args.case_sensitive) {
+            Ok(m) => grind_matches.push(m),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
     }
 
     // Validate and create matcher
-    let matcher = match VanityMatcher::new(args.prefix, args.suffix, args.case_sensitive) {
+    let matcher = match VanityMatcher::new(grind_matches, args.case_sensitive) {
         Ok(m) => Arc::new(m),
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -168,6 +533,41 @@ fn main() {
         }
     };
 
+    // Make sure the output directory exists before we start searching
+    if let Some(ref dir) = args.output_dir {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            eprintln!("Error: failed to create output directory {}: {}", dir.display(), e);
+            std::process::exit(1);
+        }
+    }
+
+    if args.derivation_path.is_some() && !args.use_mnemonic {
+        eprintln!("Error: --derivation-path requires --use-mnemonic");
+        std::process::exit(1);
+    }
+
+    let mnemonic_config = if args.use_mnemonic {
+        let derivation_path = match &args.derivation_path {
+            Some(path) => match parse_derivation_path(path) {
+                Ok(p) => Some(p),
+                Err(e) => {
+                    eprintln!("Error: invalid --derivation-path '{}': {}", path, e);
+                    std::process::exit(1);
+                }
+            },
+            None => None,
+        };
+        Some(MnemonicConfig {
+            mnemonic_type: mnemonic_type_from_word_count(args.word_count),
+            language: args.language,
+            passphrase: args.passphrase.clone(),
+            derivation_path,
+        })
+    } else {
+        None
+    };
+    let mnemonic_config = Arc::new(mnemonic_config);
+
     // Set up thread pool
     let num_threads = args.threads.unwrap_or_else(num_cpus::get);
     rayon::ThreadPoolBuilder::new()
@@ -175,32 +575,67 @@ fn main() {
         .build_global()
         .unwrap();
 
-    let plural = if args.count > 1 { "addresses" } else { "address" };
-    println!("🔍 Searching for {} Solana vanity {} with {}", args.count, plural, matcher.description());
+    let target_total = matcher.target_total();
+    let plural = if target_total > 1 { "addresses" } else { "address" };
+    println!("🔍 Searching for {} Solana vanity {} matching {}", target_total, plural, matcher.description());
     println!("⚙️  Using {} threads", num_threads);
+
+    if args.use_mnemonic {
+        println!("🔑 Deriving keypairs from fresh BIP39 mnemonics (this is much slower than the default)");
+    }
+
+    let expected_attempts = matcher.expected_attempts();
+    println!("📈 Expected attempts: ~{:.3e}", expected_attempts);
+
+    if expected_attempts > DIFFICULTY_CONFIRMATION_THRESHOLD && !args.yes {
+        eprintln!(
+            "⚠️  This pattern is expected to take ~{:.3e} attempts, which is unlikely to finish in a reasonable time.",
+            expected_attempts
+        );
+        eprintln!("   Re-run with --yes to proceed anyway.");
+        std::process::exit(1);
+    }
+
+    print!("📏 Calibrating throughput... ");
+    use std::io::Write;
+    std::io::stdout().flush().unwrap();
+    let calibration_rate = calibrate_rate(num_threads, &mnemonic_config);
+    println!("{:.0} attempts/sec", calibration_rate);
+    println!("⏱️  Estimated time: {}", format_duration(expected_attempts / calibration_rate));
     println!("⏳ This may take a while...\n");
 
     let found = Arc::new(AtomicBool::new(false));
+    let interrupted = Arc::new(AtomicBool::new(false));
     let attempts = Arc::new(AtomicU64::new(0));
-    let results: Arc<Mutex<Vec<(Keypair, String)>>> = Arc::new(Mutex::new(Vec::new()));
-    let found_count = Arc::new(AtomicUsize::new(0));
+    let results: FoundResults = Arc::new(Mutex::new(Vec::new()));
     let start_time = Instant::now();
 
+    // On Ctrl-C, signal every worker to stop so `main` can report whatever
+    // was found so far instead of the OS just killing the process.
+    {
+        let found = Arc::clone(&found);
+        let interrupted = Arc::clone(&interrupted);
+        ctrlc::set_handler(move || {
+            interrupted.store(true, Ordering::Relaxed);
+            found.store(true, Ordering::Relaxed);
+        })
+        .expect("Error setting Ctrl-C handler");
+    }
+
     // Spawn verbose reporting thread if requested
     let verbose_handle = if args.verbose {
         let attempts_clone = Arc::clone(&attempts);
         let found_clone = Arc::clone(&found);
-        let found_count_clone = Arc::clone(&found_count);
-        let target_count = args.count;
+        let results_clone = Arc::clone(&results);
         Some(std::thread::spawn(move || {
             while !found_clone.load(Ordering::Relaxed) {
                 std::thread::sleep(std::time::Duration::from_secs(1));
                 let current_attempts = attempts_clone.load(Ordering::Relaxed);
-                let current_found = found_count_clone.load(Ordering::Relaxed);
+                let current_found = results_clone.lock().unwrap().len();
                 let elapsed = start_time.elapsed().as_secs_f64();
                 let rate = current_attempts as f64 / elapsed;
-                print!("\r⚡ Attempts: {} | Found: {}/{} | Rate: {:.0} attempts/sec", 
-                    current_attempts, current_found, target_count, rate);
+                print!("\r⚡ Attempts: {} | Found: {}/{} | Rate: {:.0} attempts/sec",
+                    current_attempts, current_found, target_total, rate);
                 use std::io::Write;
                 std::io::stdout().flush().unwrap();
             }
@@ -215,11 +650,10 @@ fn main() {
         .for_each(|_| {
             generate_vanity_address(
                 Arc::clone(&matcher),
+                Arc::clone(&mnemonic_config),
                 Arc::clone(&found),
                 Arc::clone(&attempts),
                 Arc::clone(&results),
-                Arc::clone(&found_count),
-                args.count,
             )
         });
 
@@ -236,31 +670,47 @@ fn main() {
         println!(); // New line after progress indicator
     }
 
+    if interrupted.load(Ordering::Relaxed) {
+        println!("⏹️  Interrupted — reporting partial results\n");
+    }
+
     if !final_results.is_empty() {
         let plural = if final_results.len() > 1 { "addresses" } else { "address" };
         println!("✅ Found {} vanity {}!\n", final_results.len(), plural);
-        
-        for (i, (keypair, address)) in final_results.iter().enumerate() {
+
+        for (i, found_key) in final_results.iter().enumerate() {
             if final_results.len() > 1 {
                 println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
                 println!("Address #{}", i + 1);
             }
-            println!("📍 Public Key:  {}", address);
-            println!("🔑 Private Key: {}", format_secret_key(keypair));
+            println!("📍 Public Key:  {}", found_key.address);
+            println!("🔑 Private Key: {}", format_secret_key(&found_key.keypair));
+
+            if let Some(phrase) = &found_key.mnemonic {
+                println!("📝 Mnemonic:    {}", phrase);
+            }
+
+            if let Some(ref dir) = args.output_dir {
+                match write_keypair_json(&found_key.keypair, dir, &found_key.address, args.force) {
+                    Ok(path) => println!("💾 Saved to:    {}", path.display()),
+                    Err(e) => eprintln!("⚠️  {}", e),
+                }
+            }
+
             println!();
         }
-        
+
         if final_results.len() > 1 {
             println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         }
-        
+
         println!("📊 Statistics:");
         println!("   Total Attempts: {}", total_attempts);
         println!("   Addresses Found: {}", final_results.len());
         println!("   Time: {:.2}s", elapsed.as_secs_f64());
         println!("   Rate: {:.0} attempts/sec", total_attempts as f64 / elapsed.as_secs_f64());
         println!("   Avg per address: {:.0} attempts", total_attempts as f64 / final_results.len() as f64);
-        
+
         println!("\n⚠️  IMPORTANT: Save your private keys securely!");
         println!("   You can import them using: solana-keygen recover");
     } else {
@@ -276,3 +726,39 @@ mod num_cpus {
             .unwrap_or(1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_fold_size_excludes_letters_with_no_valid_opposite_case() {
+        // 'i' and 'o' are valid Base58 lowercase letters whose uppercase forms
+        // ('I', 'O') are not part of the alphabet, so they only match themselves.
+        assert_eq!(case_fold_size('i'), 1);
+        assert_eq!(case_fold_size('o'), 1);
+        // 'l' is the mirror case: not in the alphabet itself, so a lowercased
+        // 'l' pattern only matches the valid uppercase 'L'.
+        assert_eq!(case_fold_size('l'), 1);
+        // An ordinary letter with both cases present in the alphabet.
+        assert_eq!(case_fold_size('a'), 2);
+    }
+
+    #[test]
+    fn expected_attempts_per_hit_matches_for_i_and_o_patterns() {
+        let iii = GrindMatch::new("iii".to_string(), String::new(), 1);
+        let ooo = GrindMatch::new("ooo".to_string(), String::new(), 1);
+        let ioi = GrindMatch::new("ioi".to_string(), String::new(), 1);
+        let abc = GrindMatch::new("abc".to_string(), String::new(), 1);
+
+        let case_insensitive_expected = 58f64.powi(3);
+        assert!((iii.expected_attempts_per_hit(false) - case_insensitive_expected).abs() < 1e-6);
+        assert!((ooo.expected_attempts_per_hit(false) - case_insensitive_expected).abs() < 1e-6);
+        assert!((ioi.expected_attempts_per_hit(false) - case_insensitive_expected).abs() < 1e-6);
+
+        // A pattern with no 'i'/'o'/'l' doubles its probability per position,
+        // halving the expected attempts per position.
+        let abc_expected = (58f64 / 2.0).powi(3);
+        assert!((abc.expected_attempts_per_hit(false) - abc_expected).abs() < 1e-6);
+    }
+}